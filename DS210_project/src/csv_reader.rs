@@ -1,9 +1,16 @@
-// import 
+// import
 use csv::ReaderBuilder;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
 use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// directory where parsed CSV snapshots are cached, keyed by content hash
+const CACHE_DIR: &str = ".asteroid_cache";
 
 // define a struct to represent the rows of the asteroid dataset
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AsteroidData {
     // each type is a given data type based on their function in the dataset
     pub des: String,
@@ -18,11 +25,65 @@ pub struct AsteroidData {
     pub t_sigma_f: String,
 }
 
-// creates a function to parse the CSV file into a vector of AsteroidData
+// hashes the raw file bytes with SHA3-256, used as the cache key
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = Sha3_256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+fn cache_path(digest: &str) -> PathBuf {
+    Path::new(CACHE_DIR).join(format!("{}.bin", digest))
+}
+
+// compresses a bincode snapshot with LZ4 before it is written to disk
+#[cfg(feature = "lz4_cache")]
+fn compress(bytes: &[u8]) -> Vec<u8> {
+    lz4_flex::compress_prepend_size(bytes)
+}
+
+#[cfg(feature = "lz4_cache")]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(lz4_flex::decompress_size_prepended(bytes)?)
+}
+
+#[cfg(not(feature = "lz4_cache"))]
+fn compress(bytes: &[u8]) -> Vec<u8> {
+    bytes.to_vec()
+}
+
+#[cfg(not(feature = "lz4_cache"))]
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(bytes.to_vec())
+}
+
+// reads and parses the CSV file, caching the parsed records as a content-hashed bincode
+// snapshot so re-running on an unchanged file skips re-parsing entirely
 pub fn read_csv(file_path: String) -> Result<Vec<AsteroidData>, Box<dyn Error>> {
+    let raw_bytes = fs::read(&file_path)?;
+    let digest = content_hash(&raw_bytes);
+    let cache_file = cache_path(&digest);
+
+    if cache_file.exists() {
+        let cached = fs::read(&cache_file)?;
+        let decompressed = decompress(&cached)?;
+        return Ok(bincode::deserialize(&decompressed)?);
+    }
+
+    let records = parse_csv(&file_path)?;
+
+    fs::create_dir_all(CACHE_DIR)?;
+    let encoded = bincode::serialize(&records)?;
+    fs::write(&cache_file, compress(&encoded))?;
+
+    Ok(records)
+}
+
+// the original CSV-parsing logic, run on a cache miss
+fn parse_csv(file_path: &str) -> Result<Vec<AsteroidData>, Box<dyn Error>> {
     let mut reader = ReaderBuilder::new()
         .has_headers(true)
-        .from_path(&file_path)?; // fixed variable name that calls the local path 
+        .from_path(file_path)?; // fixed variable name that calls the local path
 
     let headers = reader.headers()?.clone();
     let des_idx = headers.iter().position(|h| h == "des").ok_or("Missing header 'des'")?;
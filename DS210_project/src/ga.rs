@@ -0,0 +1,223 @@
+// genetic algorithm for learning a weighted hazard-scoring function
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+use std::collections::HashSet;
+
+use crate::csv_reader::AsteroidData;
+
+// min and max of a feature across the dataset, used to normalize it into [0, 1]
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl FeatureRange {
+    // scales a raw value into [0, 1]; a flat range (max == min) normalizes everything to 0.0
+    pub fn normalize(&self, value: f64) -> f64 {
+        if (self.max - self.min).abs() < f64::EPSILON {
+            0.0
+        } else {
+            (value - self.min) / (self.max - self.min)
+        }
+    }
+
+    fn of(values: impl Iterator<Item = f64>) -> FeatureRange {
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for v in values {
+            if v < min {
+                min = v;
+            }
+            if v > max {
+                max = v;
+            }
+        }
+        FeatureRange { min, max }
+    }
+}
+
+// min-max ranges for the four hazard features, computed once over the whole dataset so
+// weights learned from them stay comparable
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureRanges {
+    pub dist_min: FeatureRange,
+    pub dist_max: FeatureRange,
+    pub v_rel: FeatureRange,
+    pub v_inf: FeatureRange,
+}
+
+impl FeatureRanges {
+    pub fn compute(data: &[AsteroidData]) -> FeatureRanges {
+        FeatureRanges {
+            dist_min: FeatureRange::of(data.iter().map(|a| a.dist_min)),
+            dist_max: FeatureRange::of(data.iter().map(|a| a.dist_max)),
+            v_rel: FeatureRange::of(data.iter().map(|a| a.v_rel)),
+            v_inf: FeatureRange::of(data.iter().map(|a| a.v_inf)),
+        }
+    }
+
+    // normalizes the four features of a single record into [0, 1]
+    pub fn normalize(&self, a: &AsteroidData) -> [f64; 4] {
+        [
+            self.dist_min.normalize(a.dist_min),
+            self.dist_max.normalize(a.dist_max),
+            self.v_rel.normalize(a.v_rel),
+            self.v_inf.normalize(a.v_inf),
+        ]
+    }
+}
+
+// a candidate scoring function: score = w0 + w1*dist_min + w2*dist_max + w3*v_rel + w4*v_inf,
+// evaluated over normalized features
+pub type Weights = [f64; 5];
+
+// scores a record's normalized features with a weight vector
+pub fn score_with_weights(weights: &Weights, features: &[f64; 4]) -> f64 {
+    weights[0]
+        + weights[1] * features[0]
+        + weights[2] * features[1]
+        + weights[3] * features[2]
+        + weights[4] * features[3]
+}
+
+// knobs for the genetic algorithm search
+#[derive(Debug, Clone, Copy)]
+pub struct GaConfig {
+    pub population_size: usize,
+    pub generations: usize,
+    pub tournament_size: usize,
+    pub mutation_rate: f64,    // probability that any given weight is perturbed
+    pub mutation_std_dev: f64, // standard deviation of the perturbation
+    pub top_quantile: f64,     // e.g. 0.1 means "top 10% of the induced ranking"
+}
+
+impl Default for GaConfig {
+    fn default() -> Self {
+        GaConfig {
+            population_size: 100,
+            generations: 200,
+            tournament_size: 3,
+            mutation_rate: 0.1,
+            mutation_std_dev: 0.2,
+            top_quantile: 0.1,
+        }
+    }
+}
+
+// fraction of labeled PHAs that land in the top `top_quantile` of the ranking a weight
+// vector induces over the dataset; higher is better
+fn fitness(weights: &Weights, features: &[[f64; 4]], labels: &[bool], top_quantile: f64) -> f64 {
+    let total_hazardous = labels.iter().filter(|&&is_hazard| is_hazard).count();
+    if total_hazardous == 0 {
+        return 0.0;
+    }
+
+    let mut scored: Vec<(f64, bool)> = features
+        .iter()
+        .zip(labels.iter())
+        .map(|(f, &is_hazard)| (score_with_weights(weights, f), is_hazard))
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let cutoff = ((scored.len() as f64) * top_quantile).ceil() as usize;
+    let cutoff = cutoff.clamp(1, scored.len());
+
+    let hazardous_in_top = scored[..cutoff].iter().filter(|(_, is_hazard)| *is_hazard).count();
+    hazardous_in_top as f64 / total_hazardous as f64
+}
+
+// picks the fitter of `tournament_size` randomly drawn individuals
+fn tournament_select<'a>(
+    population: &'a [Weights],
+    fitnesses: &[f64],
+    tournament_size: usize,
+    rng: &mut impl Rng,
+) -> &'a Weights {
+    let mut best_idx = rng.gen_range(0..population.len());
+    for _ in 1..tournament_size {
+        let idx = rng.gen_range(0..population.len());
+        if fitnesses[idx] > fitnesses[best_idx] {
+            best_idx = idx;
+        }
+    }
+    &population[best_idx]
+}
+
+// for each weight, either inherits one parent's value verbatim or averages both parents
+fn crossover(a: &Weights, b: &Weights, rng: &mut impl Rng) -> Weights {
+    let mut child: Weights = [0.0; 5];
+    for i in 0..child.len() {
+        child[i] = if rng.gen_bool(0.5) {
+            if rng.gen_bool(0.5) { a[i] } else { b[i] }
+        } else {
+            (a[i] + b[i]) / 2.0
+        };
+    }
+    child
+}
+
+// perturbs each weight with probability `mutation_rate` by a sample from N(0, mutation_std_dev)
+fn mutate(weights: &mut Weights, config: &GaConfig, rng: &mut impl Rng) {
+    let normal = Normal::new(0.0, config.mutation_std_dev).expect("mutation_std_dev must be positive");
+    for w in weights.iter_mut() {
+        if rng.gen_bool(config.mutation_rate) {
+            *w += normal.sample(rng);
+        }
+    }
+}
+
+// evolves a population of weight vectors and returns the fittest one found
+pub fn train_weights(data: &[AsteroidData], known_hazardous: &HashSet<String>, config: &GaConfig) -> Weights {
+    let ranges = FeatureRanges::compute(data);
+    let features: Vec<[f64; 4]> = data.iter().map(|a| ranges.normalize(a)).collect();
+    let labels: Vec<bool> = data.iter().map(|a| known_hazardous.contains(&a.des)).collect();
+
+    let mut rng = rand::thread_rng();
+
+    // random initial population of weight vectors
+    let mut population: Vec<Weights> = (0..config.population_size)
+        .map(|_| {
+            let mut w: Weights = [0.0; 5];
+            for wi in w.iter_mut() {
+                *wi = rng.gen_range(-1.0..1.0);
+            }
+            w
+        })
+        .collect();
+
+    let mut best = population[0];
+    let mut best_fitness = f64::NEG_INFINITY;
+
+    for _ in 0..config.generations {
+        let fitnesses: Vec<f64> = population
+            .iter()
+            .map(|w| fitness(w, &features, &labels, config.top_quantile))
+            .collect();
+
+        if let Some((idx, &f)) = fitnesses
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        {
+            if f > best_fitness {
+                best_fitness = f;
+                best = population[idx];
+            }
+        }
+
+        // elitism: the best individual found so far always survives into the next generation
+        let mut next_generation = vec![best];
+        while next_generation.len() < config.population_size {
+            let parent_a = tournament_select(&population, &fitnesses, config.tournament_size, &mut rng);
+            let parent_b = tournament_select(&population, &fitnesses, config.tournament_size, &mut rng);
+            let mut child = crossover(parent_a, parent_b, &mut rng);
+            mutate(&mut child, config, &mut rng);
+            next_generation.push(child);
+        }
+
+        population = next_generation;
+    }
+
+    best
+}
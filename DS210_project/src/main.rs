@@ -1,11 +1,36 @@
-use petgraph::graph::Graph;
-use std::collections::HashMap;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::error::Error;
 mod csv_reader;
 use csv_reader::read_csv;
+mod ga;
+use ga::{FeatureRanges, Weights};
+#[cfg(test)]
+mod test;
+
+// wraps f64 so it can be used in a BinaryHeap, which requires Ord
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OrderedF64(f64);
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // a malformed CSV cell can parse to NaN; fall back to Equal instead of panicking
+        self.0.partial_cmp(&other.0).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
 
 // build a graph where nodes represent asteroids and edges indicate hazard comparisons
-pub fn build_hazard_graph(data: &Vec<csv_reader::AsteroidData>, dist_threshold: f64, velocity_threshold: f64) -> Graph<(String, f64, f64), f64> {
+pub fn build_hazard_graph(data: &[csv_reader::AsteroidData], dist_threshold: f64, velocity_threshold: f64) -> Graph<(String, f64, f64), f64> {
     let mut graph = Graph::<(String, f64, f64), f64>::new(); // new mutable graph
     let mut node_map = HashMap::new(); // new mutable HashMap
 
@@ -40,18 +65,68 @@ pub fn build_hazard_graph(data: &Vec<csv_reader::AsteroidData>, dist_threshold:
     graph
 }
 
+// finds the path between two named asteroids that minimizes cumulative hazard-score
+// difference, i.e. the smoothest chain of comparable objects between start and goal
+pub fn shortest_hazard_path(
+    graph: &Graph<(String, f64, f64), f64>,
+    start_des: &str,
+    goal_des: &str,
+) -> Option<(Vec<NodeIndex>, f64)> {
+    let start = graph.node_indices().find(|&i| graph[i].0 == start_des)?; // locate start node by name
+    let goal = graph.node_indices().find(|&i| graph[i].0 == goal_des)?; // locate goal node by name
+
+    let mut dist: HashMap<NodeIndex, f64> = HashMap::new(); // best-known distance to each node
+    let mut prev: HashMap<NodeIndex, NodeIndex> = HashMap::new(); // predecessor on the best-known path
+    let mut heap: BinaryHeap<(Reverse<OrderedF64>, NodeIndex)> = BinaryHeap::new(); // min-priority queue on distance
+
+    dist.insert(start, 0.0);
+    heap.push((Reverse(OrderedF64(0.0)), start));
+
+    while let Some((Reverse(OrderedF64(d)), node)) = heap.pop() {
+        if node == goal {
+            // reconstruct the path by walking predecessors back to the start, then reversing
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(&p) = prev.get(&current) {
+                path.push(p);
+                current = p;
+            }
+            path.reverse();
+            return Some((path, d));
+        }
+
+        if d > *dist.get(&node).unwrap_or(&f64::INFINITY) {
+            continue; // stale heap entry; a shorter path to this node was already found
+        }
+
+        for edge in graph.edges(node) {
+            let neighbor = edge.target();
+            let next_dist = d + edge.weight(); // relax the edge
+            if next_dist < *dist.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                dist.insert(neighbor, next_dist);
+                prev.insert(neighbor, node);
+                heap.push((Reverse(OrderedF64(next_dist)), neighbor));
+            }
+        }
+    }
+
+    None // goal is unreachable from start
+}
+
+// the v_rel / dist_min hazard formula, shared by the ranking functions below
+fn hazard_score(dist_min: f64, v_rel: f64) -> f64 {
+    if dist_min > 0.0 { // ensures that the distance is not zero
+        (v_rel / dist_min) / 1_000_000.0 // scale down hazard scores
+    } else {
+        f64::INFINITY // assign very high hazard for zero distances (as it would be on a collision path)
+    }
+}
+
 // rank asteroids based on hazard score and include details in the result
-pub fn rank_hazardous_asteroids(data: &Vec<csv_reader::AsteroidData>) -> Vec<(String, f64, f64, String)> {
+pub fn rank_hazardous_asteroids(data: &[csv_reader::AsteroidData]) -> Vec<(String, f64, f64, String)> {
     let mut ranked_asteroids = data
         .iter()
-        .map(|a| {
-            let hazard_score = if a.dist_min > 0.0 { // ensures that the distance is not zero
-                (a.v_rel / a.dist_min) / 1_000_000.0 // scale down hazard scores
-            } else {
-                f64::INFINITY // assign very high hazard for zero distances (as it would be on a collision path)
-            };
-            (a.des.clone(), hazard_score, a.dist_min, a.cd.clone()) // clones the score
-        })
+        .map(|a| (a.des.clone(), hazard_score(a.dist_min, a.v_rel), a.dist_min, a.cd.clone())) // clones the score
         .collect::<Vec<_>>(); // collects the outcome
 
     // sort by hazard score in descending order
@@ -62,6 +137,75 @@ pub fn rank_hazardous_asteroids(data: &Vec<csv_reader::AsteroidData>) -> Vec<(St
     ranked_asteroids
 }
 
+// pairs a hazard score with its asteroid record for use in a BinaryHeap
+#[derive(Debug, Clone, PartialEq)]
+struct ScoredAsteroid {
+    score: OrderedF64,
+    record: (String, f64, f64, String),
+}
+
+impl Eq for ScoredAsteroid {}
+
+impl PartialOrd for ScoredAsteroid {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredAsteroid {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+// keeps only the k highest hazard scores using a bounded min-heap, giving O(n log k) time
+// and O(k) memory instead of the full sort's O(n log n) time and O(n) memory
+pub fn top_k_hazardous_asteroids(data: &[csv_reader::AsteroidData], k: usize) -> Vec<(String, f64, f64, String)> {
+    let mut heap: BinaryHeap<Reverse<ScoredAsteroid>> = BinaryHeap::new(); // min-heap: smallest score stays on top
+
+    for a in data {
+        let score = hazard_score(a.dist_min, a.v_rel);
+
+        heap.push(Reverse(ScoredAsteroid {
+            score: OrderedF64(score),
+            record: (a.des.clone(), score, a.dist_min, a.cd.clone()),
+        }));
+
+        if heap.len() > k {
+            heap.pop(); // drop the current minimum once we exceed k entries
+        }
+    }
+
+    // drain the k survivors and sort them descending for display
+    let mut top_k: Vec<(String, f64, f64, String)> = heap.into_iter().map(|Reverse(s)| s.record).collect();
+    top_k.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    top_k
+}
+
+// rank asteroids using a GA-trained weight vector instead of the hardcoded ratio
+pub fn rank_hazardous_asteroids_with_weights(
+    data: &[csv_reader::AsteroidData],
+    ranges: &FeatureRanges,
+    weights: &Weights,
+) -> Vec<(String, f64, f64, String)> {
+    let mut ranked_asteroids = data
+        .iter()
+        .map(|a| {
+            let hazard_score = ga::score_with_weights(weights, &ranges.normalize(a));
+            (a.des.clone(), hazard_score, a.dist_min, a.cd.clone())
+        })
+        .collect::<Vec<_>>();
+
+    ranked_asteroids.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    ranked_asteroids
+}
+
 // cluster the asteroids based on their hazard score
 pub fn cluster_asteroids_by_hazard(data: &[(String, f64, f64, String)]) -> HashMap<String, Vec<(String, f64, f64, String)>> {
     // define hazard score thresholds for clusters
@@ -84,7 +228,7 @@ pub fn cluster_asteroids_by_hazard(data: &[(String, f64, f64, String)]) -> HashM
         let score = asteroid.1; // score of asteroid  1
         for (min, max, label) in &thresholds { // iterates over tuples in thresholds
             if score >= *min && score < *max { // compares the score to the max and min of the range
-                clusters.get_mut(&label.to_string()).unwrap().push(asteroid.clone()); // adds score to cluster if it falls within the range
+                clusters.get_mut(*label).unwrap().push(asteroid.clone()); // adds score to cluster if it falls within the range
                 break;
             }
         }
@@ -93,6 +237,55 @@ pub fn cluster_asteroids_by_hazard(data: &[(String, f64, f64, String)]) -> HashM
     clusters
 }
 
+// count, maximum, minimum, mean, and median hazard score within a bucket of asteroids
+#[derive(Debug, Clone, Copy)]
+pub struct HazardStats {
+    pub count: usize,
+    pub max: f64,
+    pub min: f64,
+    pub mean: f64,
+    pub median: f64,
+}
+
+impl HazardStats {
+    // computes the stats over a slice of hazard scores
+    fn from_scores(scores: &[f64]) -> HazardStats {
+        let count = scores.len();
+        if count == 0 {
+            return HazardStats { count: 0, max: 0.0, min: 0.0, mean: 0.0, median: 0.0 };
+        }
+
+        let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let min = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+        let mean = scores.iter().sum::<f64>() / count as f64;
+
+        let mut sorted = scores.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2.0
+        } else {
+            sorted[count / 2]
+        };
+
+        HazardStats { count, max, min, mean, median }
+    }
+}
+
+// summarizes hazard-score statistics per cluster, plus a "Whole Dataset" rollup across all clusters
+pub fn summarize_hazards(clusters: &HashMap<String, Vec<(String, f64, f64, String)>>) -> HashMap<String, HazardStats> {
+    let mut summary = HashMap::new();
+    let mut all_scores = Vec::new();
+
+    for (label, asteroids) in clusters {
+        let scores: Vec<f64> = asteroids.iter().map(|a| a.1).collect();
+        all_scores.extend(scores.iter().cloned());
+        summary.insert(label.clone(), HazardStats::from_scores(&scores));
+    }
+
+    summary.insert("Whole Dataset".to_string(), HazardStats::from_scores(&all_scores));
+    summary
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     // define the path to the CSV file
     let file_path = "/Users/laurelpurcell/Downloads/DS210_asteroid_data.csv".to_string(); // local path
@@ -115,13 +308,15 @@ fn main() -> Result<(), Box<dyn Error>> {
     // build the hazard graph
     let dist_threshold = 0.05;  // minimum distance (in AU)
     let velocity_threshold = 10.0; // minimum relative velocity (in km/s); this is a sufficiently fast speed
-    let _hazard_graph = build_hazard_graph(&data, dist_threshold, velocity_threshold); // builds the hazard graph 
+    let hazard_graph = build_hazard_graph(&data, dist_threshold, velocity_threshold); // builds the hazard graph
 
-    // print the top 50 hazardous asteroids with their details
+    // print the top 50 hazardous asteroids with their details; uses the bounded top-k heap
+    // instead of sorting the entire dataset, since main only ever needs the top 50 anyway
+    let top_50 = top_k_hazardous_asteroids(&data, 50);
     println!("Top 50 Hazardous Asteroids:");
     println!("{:<25} {:<15} {:<15} {:<20} {:<15} {:<15}", "Asteroid", "Min Distance (AU)", "Velocity (km/s)", "Closest Approach Date", "Hazard Score", "Hazard Cluster");
     println!("{}", "-".repeat(105)); // accesses each of the necessary parts of the details
-    for asteroid in ranked_asteroids.iter().take(50) {
+    for asteroid in top_50.iter() {
         let cluster = clusters.iter().find(|(_, asteroids)| {
             asteroids.iter().any(|(n, _, _, _)| n == &asteroid.0) // iterates over the asteroids
         }).map(|(cluster_name, _)| cluster_name.clone()).unwrap_or("Unknown".to_string());
@@ -130,8 +325,48 @@ fn main() -> Result<(), Box<dyn Error>> {
             asteroid.0, asteroid.2, asteroid.1 * 1_000_000.0, asteroid.3, asteroid.1, cluster); // prints the results
     }
 
+    // print max/mean/median/min hazard statistics for each cluster and the whole dataset
+    let hazard_summary = summarize_hazards(&clusters);
+    println!("\nHazard Score Summary:");
+    println!("{:<20} {:<8} {:<12} {:<12} {:<12} {:<12}", "Cluster", "Count", "Max", "Min", "Mean", "Median");
+    println!("{}", "-".repeat(76));
+    let cluster_order = ["Negligible Risk", "Low Risk", "Moderate Risk", "Highest Risk", "Whole Dataset"];
+    for label in cluster_order {
+        if let Some(stats) = hazard_summary.get(label) {
+            println!("{:<20} {:<8} {:<12.6} {:<12.6} {:<12.6} {:<12.6}",
+                label, stats.count, stats.max, stats.min, stats.mean, stats.median);
+        }
+    }
+
+    // demonstrate the smoothest hazard path between the two most hazardous asteroids in the graph
+    if let (Some(first), Some(second)) = (ranked_asteroids.first(), ranked_asteroids.get(1)) {
+        match shortest_hazard_path(&hazard_graph, &first.0, &second.0) {
+            Some((path, total_diff)) => {
+                let names: Vec<String> = path.iter().map(|&i| hazard_graph[i].0.clone()).collect();
+                println!(
+                    "\nSmoothest hazard path from {} to {}: {} (total hazard difference: {:.6})",
+                    first.0, second.0, names.join(" -> "), total_diff
+                );
+            }
+            None => println!("\nNo hazard path found between {} and {}.", first.0, second.0),
+        }
+    }
+
+    // demonstrate the GA-trained scoring function, using the current top 5 as a stand-in
+    // labeled set (a real workflow would load known-hazardous designations from a vetted list)
+    let known_hazardous: HashSet<String> = ranked_asteroids.iter().take(5).map(|a| a.0.clone()).collect();
+    let feature_ranges = FeatureRanges::compute(&data);
+    let ga_config = ga::GaConfig { population_size: 20, generations: 20, ..Default::default() };
+    let learned_weights = ga::train_weights(&data, &known_hazardous, &ga_config);
+    let ga_ranked = rank_hazardous_asteroids_with_weights(&data, &feature_ranges, &learned_weights);
+
+    println!("\nGA-Trained Hazard Score (Top 5):");
+    for asteroid in ga_ranked.iter().take(5) {
+        println!("{:<25} {:.6}", asteroid.0, asteroid.1);
+    }
+
     // establish an interactive lookup to manually search for asteroids by name
-    use std::io::{self, Write}; 
+    use std::io::{self, Write};
 
     println!("\nEnter the name of an asteroid to retrieve details or type 'exit' to quit:"); // prompts the user to enter an asteroid name
     loop {
@@ -1,28 +1,29 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use csv_reader::AsteroidData;
+    use crate::*;
+    use crate::csv_reader::AsteroidData;
+
+    // fills in the fields the tests below don't care about with harmless placeholders
+    fn mock_asteroid(des: &str, dist_min: f64, v_rel: f64, cd: &str) -> AsteroidData {
+        AsteroidData {
+            des: des.to_string(),
+            orbit_id: "1".to_string(),
+            jd: 0.0,
+            cd: cd.to_string(),
+            dist: dist_min,
+            dist_min,
+            dist_max: dist_min,
+            v_rel,
+            v_inf: v_rel,
+            t_sigma_f: "00:00".to_string(),
+        }
+    }
 
     fn mock_asteroid_data() -> Vec<AsteroidData> {
         vec![
-            AsteroidData { 
-                des: "2023 AB".to_string(),
-                dist_min: 0.03, 
-                v_rel: 15.0, 
-                cd: "2023-01-01".to_string(),
-            },
-            AsteroidData { 
-                des: "2023 XY".to_string(),
-                dist_min: 0.1, 
-                v_rel: 8.0, 
-                cd: "2023-05-05".to_string(),
-            },
-            AsteroidData { 
-                des: "2023 ZZ".to_string(),
-                dist_min: 0.02, 
-                v_rel: 20.0, 
-                cd: "2023-07-07".to_string(),
-            },
+            mock_asteroid("2023 AB", 0.03, 15.0, "2023-01-01"),
+            mock_asteroid("2023 XY", 0.1, 8.0, "2023-05-05"),
+            mock_asteroid("2023 ZZ", 0.0001, 20.0, "2023-07-07"),
         ]
     }
 
@@ -39,7 +40,7 @@ mod tests {
         let clusters = cluster_asteroids_by_hazard(&ranked_asteroids);
         assert!(clusters.contains_key("Highest Risk"));
         assert!(clusters.contains_key("Moderate Risk"));
-        assert!(clusters["Highest Risk"].len() > 0, "At least one asteroid should be in the highest risk cluster.");
+        assert!(!clusters["Highest Risk"].is_empty(), "At least one asteroid should be in the highest risk cluster.");
     }
 
     #[test]
@@ -50,91 +51,89 @@ mod tests {
 
         let hazard_graph = build_hazard_graph(&data, dist_threshold, velocity_threshold);
 
-        // verify nodes
+        // verify nodes: only "2023 AB" and "2023 ZZ" clear both thresholds
         assert_eq!(hazard_graph.node_count(), 2, "Graph should include only asteroids meeting the thresholds.");
 
         // verify edges
         assert!(hazard_graph.edge_count() > 0, "There should be at least one edge in the hazard graph.");
     }
-}
 
+    #[test]
+    fn test_shortest_hazard_path_reachable() {
+        let data = mock_asteroid_data();
+        let hazard_graph = build_hazard_graph(&data, 0.05, 10.0);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use csv_reader::AsteroidData;
+        let (path, total) = shortest_hazard_path(&hazard_graph, "2023 AB", "2023 ZZ")
+            .expect("2023 AB and 2023 ZZ both clear the thresholds, so a path should exist");
 
-    // helper function to generate mock asteroid data
-    fn mock_asteroid_data() -> Vec<AsteroidData> {
-        vec![
-            AsteroidData { 
-                des: "Asteroid A".to_string(),
-                dist_min: 0.02, 
-                v_rel: 20.0, 
-                cd: "2024-01-01".to_string(),
-            },
-            AsteroidData { 
-                des: "Asteroid B".to_string(),
-                dist_min: 0.05, 
-                v_rel: 10.0, 
-                cd: "2024-02-01".to_string(),
-            },
-            AsteroidData { 
-                des: "Asteroid C".to_string(),
-                dist_min: 0.1, 
-                v_rel: 5.0, 
-                cd: "2024-03-01".to_string(),
-            },
-        ]
+        assert_eq!(hazard_graph[path[0]].0, "2023 AB");
+        assert_eq!(hazard_graph[*path.last().unwrap()].0, "2023 ZZ");
+        assert!(total >= 0.0);
     }
 
     #[test]
-    fn test_rank_hazardous_asteroids() {
+    fn test_shortest_hazard_path_start_equals_goal() {
         let data = mock_asteroid_data();
+        let hazard_graph = build_hazard_graph(&data, 0.05, 10.0);
 
-        // call the function
-        let ranked = rank_hazardous_asteroids(&data);
+        let (path, total) = shortest_hazard_path(&hazard_graph, "2023 AB", "2023 AB").unwrap();
+        assert_eq!(path.len(), 1);
+        assert_eq!(total, 0.0);
+    }
 
-        // verify the results
-        assert_eq!(ranked.len(), 3, "There should be three asteroids ranked.");
-        assert_eq!(ranked[0].0, "Asteroid A", "Asteroid A should be ranked first due to highest hazard score.");
-        assert!(ranked[0].1 > ranked[1].1, "Hazard score of the first asteroid should be greater than the second.");
-        assert!(ranked[1].1 > ranked[2].1, "Hazard score of the second asteroid should be greater than the third.");
+    #[test]
+    fn test_shortest_hazard_path_unreachable() {
+        let data = mock_asteroid_data();
+        let hazard_graph = build_hazard_graph(&data, 0.05, 10.0);
 
-        // verify the hazard score computation
-        let asteroid_a_score = (data[0].v_rel / data[0].dist_min) / 1_000_000.0;
-        assert!((ranked[0].1 - asteroid_a_score).abs() < 1e-6, "Hazard score of Asteroid A should match expected value.");
+        // "2023 XY" never clears the thresholds, so it is absent from the graph entirely
+        assert!(shortest_hazard_path(&hazard_graph, "2023 AB", "2023 XY").is_none());
     }
 
     #[test]
-    fn test_cluster_asteroids_by_hazard() {
+    fn test_top_k_hazardous_asteroids_respects_k() {
         let data = mock_asteroid_data();
-        let ranked = rank_hazardous_asteroids(&data);
-
-        // call the function
-        let clusters = cluster_asteroids_by_hazard(&ranked);
-
-        // verify the results
-        assert_eq!(clusters.len(), 4, "There should be four clusters.");
-
-        // check the clustering of each asteroid
-        for asteroid in ranked {
-            let hazard_score = asteroid.1;
-            let assigned_cluster = clusters.iter().find(|(_, asteroids)| {
-                asteroids.iter().any(|(name, _, _, _)| name == &asteroid.0)
-            });
-            assert!(assigned_cluster.is_some(), "Each asteroid should belong to a cluster.");
-
-            // verify correct cluster assignment based on hazard score
-            if hazard_score < 0.01 {
-                assert!(assigned_cluster.unwrap().0 == "Negligible Risk", "Asteroid with low hazard score should be in 'Negligible Risk'.");
-            } else if hazard_score < 0.05 {
-                assert!(assigned_cluster.unwrap().0 == "Low Risk", "Asteroid with moderate hazard score should be in 'Low Risk'.");
-            } else if hazard_score < 0.1 {
-                assert!(assigned_cluster.unwrap().0 == "Moderate Risk", "Asteroid with higher hazard score should be in 'Moderate Risk'.");
-            } else {
-                assert!(assigned_cluster.unwrap().0 == "Highest Risk", "Asteroid with the highest hazard score should be in 'Highest Risk'.");
-            }
-        }
+        let top = top_k_hazardous_asteroids(&data, 2);
+
+        assert_eq!(top.len(), 2);
+        assert!(top[0].1 >= top[1].1, "Results should be sorted by hazard score descending.");
+    }
+
+    #[test]
+    fn test_top_k_hazardous_asteroids_k_zero() {
+        let data = mock_asteroid_data();
+        assert!(top_k_hazardous_asteroids(&data, 0).is_empty());
+    }
+
+    #[test]
+    fn test_top_k_hazardous_asteroids_k_larger_than_data() {
+        let data = mock_asteroid_data();
+        let top = top_k_hazardous_asteroids(&data, 100);
+        assert_eq!(top.len(), data.len());
+    }
+
+    #[test]
+    fn test_hazard_stats_empty() {
+        let stats = HazardStats::from_scores(&[]);
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.max, 0.0);
+        assert_eq!(stats.min, 0.0);
+    }
+
+    #[test]
+    fn test_hazard_stats_odd_count_median() {
+        let stats = HazardStats::from_scores(&[1.0, 3.0, 2.0]);
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.max, 3.0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.0);
+        assert!((stats.mean - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hazard_stats_even_count_median() {
+        let stats = HazardStats::from_scores(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.median, 2.5);
     }
 }